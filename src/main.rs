@@ -1,11 +1,139 @@
 use std::{
     env,
     fs,
-    io::{self, BufRead, BufReader, Write},
+    io::{self, BufRead, BufReader, Read, Write},
     path::{Path, PathBuf},
     process::{Command, ExitCode, ExitStatus, Stdio},
 };
 
+/// Turn a child's `ExitStatus` into the `ExitCode` windo itself exits with,
+/// faithfully proxying the child's fate rather than collapsing it to 1.
+/// A normal exit relays the real code; a signal-terminated child (no exit
+/// code on Unix) exits with `128 + signal`, matching what a POSIX shell
+/// reports for `$?` in the same situation.
+fn exit_code_for(status: ExitStatus) -> ExitCode {
+    if let Some(code) = status.code() {
+        return ExitCode::from(code as u8);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return ExitCode::from((128 + signal) as u8);
+        }
+    }
+
+    ExitCode::from(1)
+}
+
+/// Whether argument paths should be rewritten from WSL to Windows form
+/// before being handed to the child process.
+///
+/// Enabled by default; set `WINDO_TRANSLATE_PATHS=0` (or `false`/`no`) to
+/// disable for commands whose arguments merely look path-like.
+fn should_translate_paths() -> bool {
+    match env::var("WINDO_TRANSLATE_PATHS") {
+        Ok(val) => !matches!(val.as_str(), "0" | "false" | "no"),
+        Err(_) => true,
+    }
+}
+
+/// Heuristic: does `arg` look like a filesystem path rather than a flag,
+/// URL, or other opaque token?
+fn looks_like_path_arg(arg: &str) -> bool {
+    if arg.contains("://") {
+        // URLs should be passed through untouched.
+        return false;
+    }
+
+    // Check this before the generic leading-'/' guard below: every
+    // `/mnt/<drive>/...` WSL path also starts with `/`, so it would
+    // otherwise be rejected as a `/flag`-style switch.
+    let bytes = arg.as_bytes();
+    let is_mnt_path = arg.starts_with("/mnt/")
+        && bytes.len() > 6
+        && bytes[5].is_ascii_alphabetic()
+        && bytes[6] == b'/';
+    if is_mnt_path {
+        return true;
+    }
+
+    if arg.starts_with('-') || arg.starts_with('/') {
+        // `-flag` or a Windows-style `/flag` switch.
+        return false;
+    }
+
+    Path::new(arg).exists()
+}
+
+/// Rewrite WSL-style path arguments into Windows form via `wslpath -w`.
+///
+/// Arguments after a literal `--` separator, and anything that doesn't
+/// look path-like per [`looks_like_path_arg`], are left untouched. All
+/// candidate paths are resolved in a single batched `wslpath` invocation
+/// rather than one process per argument.
+fn translate_args_for_windows(args: &[String]) -> Vec<String> {
+    if !should_translate_paths() {
+        return args.to_vec();
+    }
+
+    let mut result = args.to_vec();
+
+    let mut candidates = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--" {
+            break;
+        }
+        if looks_like_path_arg(arg) {
+            candidates.push(i);
+        }
+    }
+
+    if candidates.is_empty() {
+        return result;
+    }
+
+    // Resolve relative paths against the current directory ourselves so
+    // that a bare relative argument still works when the current
+    // directory is itself a UNC mount (see `is_on_unc_path`): `wslpath`
+    // only needs to see the fully resolved WSL path, wherever it lives.
+    let current_dir = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let inputs: Vec<PathBuf> = candidates
+        .iter()
+        .map(|&i| {
+            let arg = Path::new(&args[i]);
+            if arg.is_absolute() {
+                arg.to_path_buf()
+            } else {
+                current_dir.join(arg)
+            }
+        })
+        .collect();
+
+    let Ok(output) = Command::new("wslpath").arg("-w").args(&inputs).output() else {
+        return result;
+    };
+    if !output.status.success() {
+        return result;
+    }
+
+    let converted: Vec<&str> = std::str::from_utf8(&output.stdout)
+        .map(|s| s.lines().collect())
+        .unwrap_or_default();
+    if converted.len() != candidates.len() {
+        // Something unexpected came back; leave the original arguments alone.
+        return result;
+    }
+
+    for (&i, windows_path) in candidates.iter().zip(converted.iter()) {
+        if !windows_path.is_empty() {
+            result[i] = windows_path.to_string();
+        }
+    }
+
+    result
+}
 
 fn is_on_unc_path() -> bool {
     let Ok(current_dir) = env::current_dir() else {
@@ -32,80 +160,403 @@ fn is_on_unc_path() -> bool {
 struct Configuration {
     path: PathBuf,
     pipe: bool,
-    needs_cmd_wrapper: bool,
+    wrapper: WrapperStrategy,
+    // `.bat`/`.cmd` resolved from a UNC (network/WSL-drive) location: cmd.exe
+    // can't set its working directory there directly, so it must be run via
+    // a `pushd`/`popd` wrapper that lets cmd auto-map a temporary drive.
+    needs_unc_pushd: bool,
+}
+
+/// How a resolved executable needs to be invoked on the Windows side.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum WrapperStrategy {
+    /// Run the resolved path directly (`.exe`, `.com`).
+    None,
+    /// Run via `cmd.exe /c` (`.bat`, `.cmd`).
+    Cmd,
+    /// Run via `powershell.exe -File` (`.ps1`).
+    PowerShell,
+}
+
+struct SupportedExecutable {
+    suffix: &'static str,
+    wrapper: WrapperStrategy,
+    pipe: bool,
+}
+
+const SUPPORTED_EXECUTABLES: &[SupportedExecutable] = &[
+    SupportedExecutable { suffix: ".com", wrapper: WrapperStrategy::None, pipe: false },
+    SupportedExecutable { suffix: ".exe", wrapper: WrapperStrategy::None, pipe: false },
+    SupportedExecutable { suffix: ".bat", wrapper: WrapperStrategy::Cmd, pipe: true },
+    SupportedExecutable { suffix: ".cmd", wrapper: WrapperStrategy::Cmd, pipe: true },
+    SupportedExecutable { suffix: ".ps1", wrapper: WrapperStrategy::PowerShell, pipe: true },
+];
+
+fn supported_executable_for(suffix: &str) -> Option<&'static SupportedExecutable> {
+    SUPPORTED_EXECUTABLES
+        .iter()
+        .find(|exe| suffix.eq_ignore_ascii_case(exe.suffix))
+}
+
+/// Query the user's `%PATHEXT%` via `cmd.exe` so resolution order matches
+/// what Windows itself would pick. Each call spawns `cmd.exe` anew; the
+/// result is not cached, and a normal run calls this once.
+fn query_pathext() -> Vec<String> {
+    let fallback = || vec![".COM".to_string(), ".EXE".to_string(), ".BAT".to_string(), ".CMD".to_string()];
+
+    let Ok(output) = Command::new("cmd.exe").arg("/c").arg("echo %PATHEXT%").output() else {
+        return fallback();
+    };
+    if !output.status.success() {
+        return fallback();
+    }
+
+    let raw = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if raw.is_empty() || raw.eq_ignore_ascii_case("%pathext%") {
+        return fallback();
+    }
+
+    raw.split(';').filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
+
+/// Supported executable kinds, ordered by the user's `%PATHEXT%` precedence.
+/// Kinds windo supports but that aren't part of `%PATHEXT%` (e.g. `.ps1`,
+/// which PowerShell doesn't register there by default) are appended at the
+/// end so they're still resolvable.
+fn ordered_supported_executables() -> Vec<&'static SupportedExecutable> {
+    let mut ordered: Vec<&'static SupportedExecutable> = Vec::new();
+
+    for ext in query_pathext() {
+        if let Some(exe) = supported_executable_for(&ext) {
+            if !ordered.iter().any(|o| o.suffix == exe.suffix) {
+                ordered.push(exe);
+            }
+        }
+    }
+
+    for exe in SUPPORTED_EXECUTABLES {
+        if !ordered.iter().any(|o| o.suffix == exe.suffix) {
+            ordered.push(exe);
+        }
+    }
+
+    ordered
 }
 
 fn find_configuration(command: &str) -> Result<Configuration, String> {
     if Path::new(command).extension().is_some() {
         let path = which::which(command).map_err(|_| format!("Command '{}' not found", command))?;
-        let needs_cmd_wrapper = command.ends_with(".bat") || command.ends_with(".cmd");
-        return Ok(Configuration { path, pipe: needs_cmd_wrapper, needs_cmd_wrapper });
-    }
-
-    struct SupportedExecutable {
-        suffix: &'static str,
-        needs_cmd_wrapper: bool,
-        pipe: bool,
+        let suffix = command.rsplit_once('.').map(|(_, ext)| format!(".{}", ext)).unwrap_or_default();
+        let exe = supported_executable_for(&suffix);
+        let wrapper = exe.map_or(WrapperStrategy::None, |exe| exe.wrapper);
+        return Ok(Configuration {
+            path,
+            pipe: exe.is_some_and(|exe| exe.pipe),
+            wrapper,
+            needs_unc_pushd: wrapper == WrapperStrategy::Cmd && is_on_unc_path(),
+        });
     }
-    let supported = [
-        SupportedExecutable {
-            suffix: ".exe",
-            needs_cmd_wrapper: false,
-            pipe: false,
-        },
-        SupportedExecutable {
-            suffix: ".bat",
-            needs_cmd_wrapper: true,
-            pipe: true,
-        },
-        SupportedExecutable {
-            suffix: ".cmd",
-            needs_cmd_wrapper: true,
-            pipe: true,
-        },
-    ];
 
     let is_unc = is_on_unc_path();
 
-    let mut found_unsupported = None;
-
-    for ext in &supported {
+    for ext in ordered_supported_executables() {
         let candidate = format!("{}{}", command, ext.suffix);
         if let Ok(path) = which::which(&candidate) {
-            if ext.needs_cmd_wrapper && is_unc {
-                found_unsupported = Some(Configuration {
-                    path,
-                    pipe: ext.pipe,
-                    needs_cmd_wrapper: ext.needs_cmd_wrapper,
-                });
-            } else {
-                return Ok(Configuration {
-                    path,
-                    pipe: ext.pipe,
-                    needs_cmd_wrapper: ext.needs_cmd_wrapper,
-                });
+            return Ok(Configuration {
+                path,
+                pipe: ext.pipe,
+                wrapper: ext.wrapper,
+                needs_unc_pushd: ext.wrapper == WrapperStrategy::Cmd && is_unc,
+            });
+        }
+    }
+
+    Err(format!("Command '{}' not found", command))
+}
+
+/// Quote a single token for embedding in a `cmd.exe /c "..."` command line
+/// built by hand (as opposed to passed through `Command::arg`, which can't
+/// express the compound `pushd && ... & popd` line cmd.exe needs as one
+/// string). Embedded `"` are doubled per cmd.exe's own quoting convention
+/// so a value can't break out of its quotes and expose `&`/`|`/`^` to
+/// cmd.exe's parser. `%` is rejected outright: cmd.exe expands `%...%`
+/// during parsing regardless of quoting, so there's no way to pass one
+/// through as literal data here.
+fn quote_for_cmd(value: &str) -> Option<String> {
+    if value.contains('%') {
+        return None;
+    }
+    Some(format!("\"{}\"", value.replace('"', "\"\"")))
+}
+
+/// Build the `cmd.exe /c "pushd ... && ... & popd"` invocation used to run a
+/// `.bat`/`.cmd` script that lives on a UNC path. `pushd` against a UNC
+/// directory transparently maps a temporary drive letter and makes it the
+/// current directory, which is otherwise something `cmd.exe` refuses to do.
+fn build_unc_pushd_command(script_path: &Path, args: &[String]) -> Option<Command> {
+    let script_dir = script_path.parent()?;
+    let script_name = script_path.file_name()?.to_string_lossy().to_string();
+
+    let windows_dir = Command::new("wslpath")
+        .arg("-w")
+        .arg(script_dir)
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())?;
+
+    let mut line = format!(
+        "pushd {} && {}",
+        quote_for_cmd(&windows_dir)?,
+        quote_for_cmd(&script_name)?
+    );
+    for arg in args {
+        line.push(' ');
+        line.push_str(&quote_for_cmd(arg)?);
+    }
+    // `popd` runs after the script and would otherwise become the last
+    // command cmd.exe sees, replacing the script's real exit code with its
+    // own. Capture %ERRORLEVEL% before `popd` and relay it explicitly.
+    line.push_str(" & set WINDO_RC=%ERRORLEVEL% & popd & exit /b %WINDO_RC%");
+
+    let mut cmd = Command::new("cmd.exe");
+    cmd.arg("/c").arg(line);
+    Some(cmd)
+}
+
+/// Build the `powershell.exe -File ...` invocation used to run a `.ps1`
+/// script, converting its path to Windows form first.
+fn build_powershell_command(script_path: &Path, args: &[String]) -> Command {
+    let windows_path = Command::new("wslpath")
+        .arg("-w")
+        .arg(script_path)
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|_| script_path.display().to_string());
+
+    let mut cmd = Command::new("powershell.exe");
+    cmd.arg("-NoProfile")
+        .arg("-ExecutionPolicy")
+        .arg("Bypass")
+        .arg("-File")
+        .arg(windows_path)
+        .args(args);
+    cmd
+}
+
+/// Build the `Command` used to run a resolved executable, picking the
+/// wrapper strategy it needs. Shared by the pipe and non-pipe branches of
+/// `main` so adding a new wrapper strategy only means editing this one
+/// place.
+fn build_command(exe: &Configuration, forwarded_args: &[String]) -> Result<Command, String> {
+    if exe.needs_unc_pushd {
+        return build_unc_pushd_command(&exe.path, forwarded_args)
+            .ok_or_else(|| format!("could not build UNC-safe command for '{}'", exe.path.display()));
+    }
+
+    if exe.wrapper == WrapperStrategy::PowerShell {
+        return Ok(build_powershell_command(&exe.path, forwarded_args));
+    }
+
+    if exe.wrapper == WrapperStrategy::Cmd {
+        let windows_path = Command::new("wslpath")
+            .arg("-w")
+            .arg(&exe.path)
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .unwrap_or_else(|_| exe.path.display().to_string());
+
+        // cmd.exe re-parses its whole `/c` command line with its own
+        // grammar no matter how the argv was quoted at the OS level, so
+        // each token has to be escaped via `quote_for_cmd` here too (as
+        // the UNC-pushd path above already does) or `&`/`|`/`^`/`"` in a
+        // forwarded argument would let it inject extra commands.
+        let mut line = quote_for_cmd(&windows_path)
+            .ok_or_else(|| format!("could not quote path '{}' for cmd.exe", windows_path))?;
+        for arg in forwarded_args {
+            line.push(' ');
+            line.push_str(
+                &quote_for_cmd(arg)
+                    .ok_or_else(|| format!("argument '{}' cannot be safely passed to cmd.exe", arg))?,
+            );
+        }
+
+        let mut cmd = Command::new("cmd.exe");
+        cmd.arg("/c").arg(line);
+        return Ok(cmd);
+    }
+
+    let mut cmd = Command::new(&exe.path);
+    cmd.args(forwarded_args);
+    Ok(cmd)
+}
+
+/// Install a symlink named `<name>` pointing at windo's own executable, so
+/// that running `<name>` dispatches through the argv[0] handling in `main`
+/// (e.g. `windo install npm` lets a bare `npm` in `PATH` run `npm.cmd` via
+/// windo). Defaults to `~/bin`; pass a second argument to install elsewhere.
+fn run_install(args: &[String]) -> ExitCode {
+    let Some(name) = args.first() else {
+        eprintln!("Usage: windo install <name> [dir]");
+        return ExitCode::FAILURE;
+    };
+
+    let dir = match args.get(1) {
+        Some(dir) => PathBuf::from(dir),
+        None => match env::var_os("HOME") {
+            Some(home) => PathBuf::from(home).join("bin"),
+            None => {
+                eprintln!("Error: no install directory given and $HOME is not set");
+                return ExitCode::FAILURE;
             }
+        },
+    };
+
+    let current_exe = match env::current_exe() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Error: could not determine windo's own executable path: {}", e);
+            return ExitCode::FAILURE;
         }
+    };
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Error creating '{}': {}", dir.display(), e);
+        return ExitCode::FAILURE;
     }
 
-    if let Some(exe) = found_unsupported {
-        return Err(format!(
-            "Command '{}' found but cannot be executed from UNC path (network drive). Use .exe files or run from a local drive.",
-            exe.path.display()
-        ));
+    let link = dir.join(name);
+    if let Err(e) = std::os::unix::fs::symlink(&current_exe, &link) {
+        eprintln!("Error linking '{}' to '{}': {}", link.display(), current_exe.display(), e);
+        return ExitCode::FAILURE;
     }
 
-    Err(format!("Command '{}' not found", command))
+    println!("Installed '{}' -> {}", link.display(), current_exe.display());
+    ExitCode::SUCCESS
+}
+
+/// Parse `windo open [--browser <exe>] <path|url>` arguments, returning the
+/// target and an optional browser override. An explicit `--browser <exe>`
+/// wins over `env_browser` (windo's `$BROWSER` fallback).
+fn parse_open_args(args: &[String], env_browser: Option<String>) -> Result<(String, Option<String>), String> {
+    let mut browser_override = env_browser;
+
+    let mut rest = args;
+    if rest.first().map(String::as_str) == Some("--browser") {
+        let Some(browser) = rest.get(1) else {
+            return Err("Usage: windo open [--browser <exe>] <path|url>".to_string());
+        };
+        browser_override = Some(browser.clone());
+        rest = &rest[2..];
+    }
+
+    let Some(target) = rest.first() else {
+        return Err("Usage: windo open [--browser <exe>] <path|url>".to_string());
+    };
+
+    Ok((target.clone(), browser_override))
+}
+
+/// `windo open <path|url>` — launch a file or URL with its Windows default
+/// handler, mirroring what the `opener`/`open` crates do on other
+/// platforms. Filesystem paths are translated to Windows form first; URLs
+/// are passed through as-is. `--browser <exe>` (or `$BROWSER`) forces URLs
+/// into a specific browser instead of the registered default.
+fn run_open(args: &[String]) -> ExitCode {
+    let env_browser = env::var("BROWSER").ok().filter(|b| !b.is_empty());
+    let (target, browser_override) = match parse_open_args(args, env_browser) {
+        Ok(parsed) => parsed,
+        Err(usage) => {
+            eprintln!("{}", usage);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let is_url = target.contains("://");
+
+    let windows_target = if is_url {
+        target.clone()
+    } else {
+        Command::new("wslpath")
+            .arg("-w")
+            .arg(&target)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .unwrap_or_else(|| target.clone())
+    };
+
+    // `start`'s first argument is always taken as a window title, so an
+    // empty title is required here or a quoted path/URL would be
+    // misinterpreted as one.
+    let mut cmd = Command::new("cmd.exe");
+    cmd.arg("/c").arg("start").arg("");
+
+    if is_url {
+        if let Some(browser) = browser_override {
+            cmd.arg(browser);
+        }
+    }
+    cmd.arg(windows_target);
+
+    match cmd.status() {
+        Ok(status) => exit_code_for(status),
+        Err(e) => {
+            eprintln!("Error opening '{}': {}", target, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Where a given invocation's argv should be routed, per the multi-call
+/// (argv[0]) dispatch rule: invoked as `windo` itself, the first remaining
+/// argument selects `install`/`open`/a plain command; invoked under any
+/// other name (typically a shim symlink from `windo install`), that name
+/// is treated as the command to run.
+enum Dispatch {
+    Install(Vec<String>),
+    Open(Vec<String>),
+    Run(String, Vec<String>),
+}
+
+/// Decide what `windo` should do based on its argv, implementing the
+/// multi-call dispatch described on [`Dispatch`]. This lets a shim like
+/// `~/bin/npm -> windo` make `npm install` transparently run `npm.cmd` on
+/// the Windows side.
+fn dispatch(args: &[String]) -> Result<Dispatch, String> {
+    let invoked_as = Path::new(&args[0])
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    if invoked_as.eq_ignore_ascii_case("windo") {
+        let Some(first) = args.get(1) else {
+            return Err(format!("Usage: {} <command> [args...]", args[0]));
+        };
+        return Ok(match first.as_str() {
+            "install" => Dispatch::Install(args[2..].to_vec()),
+            "open" => Dispatch::Open(args[2..].to_vec()),
+            _ => Dispatch::Run(first.clone(), args[2..].to_vec()),
+        });
+    }
+
+    Ok(Dispatch::Run(invoked_as, args[1..].to_vec()))
 }
 
 fn main() -> ExitCode {
     let args = env::args().collect::<Vec<String>>();
-    if args.len() < 2 {
-        eprintln!("Usage: {} <command> [args...]", args[0]);
-        return ExitCode::FAILURE;
-    }
 
-    let exe = match find_configuration(&args[1]) {
+    let (command, rest) = match dispatch(&args) {
+        Ok(Dispatch::Install(args)) => return run_install(&args),
+        Ok(Dispatch::Open(args)) => return run_open(&args),
+        Ok(Dispatch::Run(command, rest)) => (command, rest),
+        Err(msg) => {
+            eprintln!("{}", msg);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let exe = match find_configuration(&command) {
         Ok(path) => path,
         Err(msg) => {
             eprintln!("Error: {}", msg);
@@ -113,29 +564,19 @@ fn main() -> ExitCode {
         }
     };
 
+    let forwarded_args = translate_args_for_windows(&rest);
+
     let status: ExitStatus = if exe.pipe {
-        let mut command = if exe.needs_cmd_wrapper {
-            let mut cmd = Command::new("cmd.exe");
-            cmd.arg("/c");
-            
-            // Convert WSL path to Windows path using wslpath
-            let windows_path = Command::new("wslpath")
-                .arg("-w")
-                .arg(&exe.path)
-                .output()
-                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
-                .unwrap_or_else(|_| exe.path.display().to_string());
-                
-            cmd.arg(windows_path);
-            cmd.args(&args[2..]);
-            cmd
-        } else {
-            let mut cmd = Command::new(&exe.path);
-            cmd.args(&args[2..]);
-            cmd
+        let mut command = match build_command(&exe, &forwarded_args) {
+            Ok(cmd) => cmd,
+            Err(msg) => {
+                eprintln!("Error: {}", msg);
+                return ExitCode::FAILURE;
+            }
         };
-        
+
         let mut child = match command
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
@@ -147,9 +588,37 @@ fn main() -> ExitCode {
             }
         };
 
+        let mut child_stdin = child.stdin.take().unwrap();
         let stdout = child.stdout.take().unwrap();
         let stderr = child.stderr.take().unwrap();
 
+        // Pump our stdin into the child's so interactive programs and
+        // shell pipelines (`echo foo | windo mytool.bat`) work. Unlike the
+        // stdout/stderr reader threads below, this one is never joined:
+        // our own stdin read blocks until EOF, and if the child exits
+        // first (e.g. an interactive terminal with no more input), there
+        // is no way to unblock that read, so joining here would hang
+        // windo itself after the child is already done. Process exit
+        // reclaims the thread instead; dropping `child_stdin` first
+        // closes the child's end of the pipe, so it never sees a
+        // half-open handle.
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            let mut stdin = io::stdin();
+            loop {
+                let n = match stdin.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => n,
+                };
+                if child_stdin.write_all(&buf[..n]).is_err() {
+                    break;
+                }
+                if child_stdin.flush().is_err() {
+                    break;
+                }
+            }
+        });
+
         let stdout_handle = std::thread::spawn(move || {
             let mut reader = BufReader::new(stdout);
             let mut line = String::new();
@@ -189,27 +658,14 @@ fn main() -> ExitCode {
 
         status
     } else {
-        let mut command = if exe.needs_cmd_wrapper {
-            let mut cmd = Command::new("cmd.exe");
-            cmd.arg("/c");
-            
-            // Convert WSL path to Windows path using wslpath
-            let windows_path = Command::new("wslpath")
-                .arg("-w")
-                .arg(&exe.path)
-                .output()
-                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
-                .unwrap_or_else(|_| exe.path.display().to_string());
-                
-            cmd.arg(windows_path);
-            cmd.args(&args[2..]);
-            cmd
-        } else {
-            let mut cmd = Command::new(&exe.path);
-            cmd.args(&args[2..]);
-            cmd
+        let mut command = match build_command(&exe, &forwarded_args) {
+            Ok(cmd) => cmd,
+            Err(msg) => {
+                eprintln!("Error: {}", msg);
+                return ExitCode::FAILURE;
+            }
         };
-        
+
         let mut child = match command.spawn() {
             Ok(child) => child,
             Err(e) => {
@@ -227,5 +683,143 @@ fn main() -> ExitCode {
         }
     };
 
-    ExitCode::from(status.code().unwrap_or(1) as u8)
+    exit_code_for(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mnt_paths_are_not_rejected_as_flags() {
+        // Every `/mnt/<drive>/...` path starts with `/`, same as a
+        // `/flag`-style switch; it must still be recognized as a path.
+        assert!(looks_like_path_arg("/mnt/c/foo"));
+        assert!(looks_like_path_arg("/mnt/c/"));
+    }
+
+    #[test]
+    fn short_or_malformed_mnt_like_args_are_not_paths() {
+        assert!(!looks_like_path_arg("/mnt/"));
+        assert!(!looks_like_path_arg("/mnt/cc/foo"));
+        assert!(!looks_like_path_arg("/mn"));
+    }
+
+    #[test]
+    fn dash_and_slash_flags_are_not_paths() {
+        assert!(!looks_like_path_arg("-x"));
+        assert!(!looks_like_path_arg("--verbose"));
+        assert!(!looks_like_path_arg("/help"));
+    }
+
+    #[test]
+    fn urls_are_passed_through_untouched() {
+        assert!(!looks_like_path_arg("https://example.com/mnt/c/foo"));
+    }
+
+    #[test]
+    fn quote_for_cmd_doubles_embedded_quotes() {
+        assert_eq!(quote_for_cmd("a\"b").unwrap(), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn quote_for_cmd_rejects_percent() {
+        // cmd.exe expands `%...%` while parsing regardless of quoting, so
+        // there's no way to pass one through as literal data.
+        assert_eq!(quote_for_cmd("100%"), None);
+    }
+
+    #[test]
+    fn quote_for_cmd_quotes_metacharacters() {
+        for value in ["a & b", "a | b", "a ^ b"] {
+            let quoted = quote_for_cmd(value).unwrap();
+            assert_eq!(quoted, format!("\"{}\"", value));
+        }
+    }
+
+    #[test]
+    fn supported_executable_for_matches_case_insensitively() {
+        assert!(supported_executable_for(".EXE").is_some());
+        assert_eq!(supported_executable_for(".bat").unwrap().wrapper, WrapperStrategy::Cmd);
+        assert_eq!(supported_executable_for(".PS1").unwrap().wrapper, WrapperStrategy::PowerShell);
+    }
+
+    #[test]
+    fn supported_executable_for_rejects_unknown_suffix() {
+        assert!(supported_executable_for(".txt").is_none());
+    }
+
+    #[test]
+    fn exit_code_for_relays_the_exit_code() {
+        use std::os::unix::process::ExitStatusExt;
+        let status = ExitStatus::from_raw(42 << 8);
+        assert_eq!(exit_code_for(status), ExitCode::from(42));
+    }
+
+    #[test]
+    fn exit_code_for_maps_signal_termination_to_128_plus_signal() {
+        use std::os::unix::process::ExitStatusExt;
+        // SIGKILL (9), encoded as a raw wait status with no exit code.
+        let status = ExitStatus::from_raw(9);
+        assert_eq!(exit_code_for(status), ExitCode::from(128 + 9));
+    }
+
+    #[test]
+    fn parse_open_args_rejects_missing_target() {
+        assert!(parse_open_args(&[], None).is_err());
+    }
+
+    #[test]
+    fn parse_open_args_rejects_browser_flag_with_no_value() {
+        assert!(parse_open_args(&["--browser".to_string()], None).is_err());
+    }
+
+    #[test]
+    fn parse_open_args_takes_explicit_browser_over_env() {
+        let args = vec!["--browser".to_string(), "chrome.exe".to_string(), "https://example.com".to_string()];
+        let (target, browser) = parse_open_args(&args, Some("firefox.exe".to_string())).unwrap();
+        assert_eq!(target, "https://example.com");
+        assert_eq!(browser, Some("chrome.exe".to_string()));
+    }
+
+    #[test]
+    fn parse_open_args_falls_back_to_env_browser() {
+        let args = vec!["https://example.com".to_string()];
+        let (target, browser) = parse_open_args(&args, Some("firefox.exe".to_string())).unwrap();
+        assert_eq!(target, "https://example.com");
+        assert_eq!(browser, Some("firefox.exe".to_string()));
+    }
+
+    #[test]
+    fn dispatch_treats_invoked_name_as_the_command_when_not_windo() {
+        let args = ["/home/user/bin/npm".to_string(), "install".to_string()];
+        match dispatch(&args).unwrap() {
+            Dispatch::Run(command, rest) => {
+                assert_eq!(command, "npm");
+                assert_eq!(rest, vec!["install".to_string()]);
+            }
+            _ => panic!("expected Dispatch::Run"),
+        }
+    }
+
+    #[test]
+    fn dispatch_recognizes_install_and_open_subcommands_when_invoked_as_windo() {
+        let args = ["windo".to_string(), "install".to_string(), "npm".to_string()];
+        match dispatch(&args).unwrap() {
+            Dispatch::Install(rest) => assert_eq!(rest, vec!["npm".to_string()]),
+            _ => panic!("expected Dispatch::Install"),
+        }
+
+        let args = ["windo".to_string(), "open".to_string(), "https://example.com".to_string()];
+        match dispatch(&args).unwrap() {
+            Dispatch::Open(rest) => assert_eq!(rest, vec!["https://example.com".to_string()]),
+            _ => panic!("expected Dispatch::Open"),
+        }
+    }
+
+    #[test]
+    fn dispatch_requires_a_command_when_invoked_as_windo() {
+        let args = ["windo".to_string()];
+        assert!(dispatch(&args).is_err());
+    }
 }